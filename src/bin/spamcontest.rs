@@ -1,7 +1,11 @@
+use dashmap::DashMap;
 use log::{debug, error, LevelFilter};
 use serenity::prelude::*;
+use spamcontest::config::GuildConfigsKey;
+use spamcontest::leaderboard::LeaderboardsKey;
 use spamcontest::Handler;
 use std::process::ExitCode;
+use std::sync::Arc;
 use std::{env, io};
 
 const TOKEN_VAR_KEY: &str = "DISCORD_TOKEN";
@@ -24,6 +28,8 @@ async fn main() -> ExitCode {
     let intents = GatewayIntents::non_privileged() | GatewayIntents::MESSAGE_CONTENT;
     let mut client = match Client::builder(token, intents)
         .event_handler(Handler::new())
+        .type_map_insert::<GuildConfigsKey>(Arc::new(DashMap::new()))
+        .type_map_insert::<LeaderboardsKey>(Arc::new(DashMap::new()))
         .await
     {
         Ok(client) => client,
@@ -0,0 +1,146 @@
+use dashmap::DashMap;
+use serde::{Deserialize, Serialize};
+use serenity::client::Context;
+use serenity::model::id::GuildId;
+use serenity::prelude::TypeMapKey;
+use std::ops::RangeInclusive;
+use std::sync::Arc;
+use std::time::Duration;
+
+use crate::storage;
+use crate::{ALLOWED_DURATION_RANGE, DEFAULT_CONTEST_DURATION, PIN_ANNOUNCEMENT_THRESHOLD};
+
+const CONFIG_DIR: &str = "guild_config";
+
+/// Absolute upper bound for any duration a guild can configure via `/spamcontest
+/// settings`, independent of the (itself configurable) [`GuildConfig::allowed_duration_range`].
+/// Without this, a guild could set e.g. `default_duration_secs` so high that
+/// `OffsetDateTime::now_utc() + duration` in `run_contest` overflows and panics.
+pub const MAX_SETTABLE_DURATION_SECS: u64 = 7 * 24 * 60 * 60;
+
+/// Per-guild settings, persisted as a TOML file under [`CONFIG_DIR`] and cached in
+/// `ctx.data` behind [`GuildConfigsKey`].
+#[derive(Clone, Serialize, Deserialize)]
+#[serde(default)]
+pub struct GuildConfig {
+    pub default_duration_secs: u64,
+    pub min_duration_secs: u64,
+    pub max_duration_secs: u64,
+    pub pin_threshold_secs: u64,
+    pub language: Language,
+}
+
+impl Default for GuildConfig {
+    fn default() -> Self {
+        Self {
+            default_duration_secs: DEFAULT_CONTEST_DURATION.as_secs(),
+            min_duration_secs: ALLOWED_DURATION_RANGE.start().as_secs(),
+            max_duration_secs: ALLOWED_DURATION_RANGE.end().as_secs(),
+            pin_threshold_secs: PIN_ANNOUNCEMENT_THRESHOLD.as_secs(),
+            language: Language::default(),
+        }
+    }
+}
+
+impl GuildConfig {
+    pub fn default_duration(&self) -> Duration {
+        Duration::from_secs(self.default_duration_secs)
+    }
+
+    pub fn allowed_duration_range(&self) -> RangeInclusive<Duration> {
+        Duration::from_secs(self.min_duration_secs)..=Duration::from_secs(self.max_duration_secs)
+    }
+
+    pub fn pin_threshold(&self) -> Duration {
+        Duration::from_secs(self.pin_threshold_secs)
+    }
+
+    /// Clamps every configured duration into `1..=MAX_SETTABLE_DURATION_SECS` (`0` is
+    /// allowed for `pin_threshold_secs`, meaning "always pin") and fixes up
+    /// `min_duration_secs`/`max_duration_secs` if they end up the wrong way round.
+    /// Called before persisting settings a guild member supplied, so malformed or
+    /// absurd input can never reach `run_contest`.
+    pub fn clamp(&mut self) {
+        self.default_duration_secs = self.default_duration_secs.clamp(1, MAX_SETTABLE_DURATION_SECS);
+        self.min_duration_secs = self.min_duration_secs.clamp(1, MAX_SETTABLE_DURATION_SECS);
+        self.max_duration_secs = self.max_duration_secs.clamp(1, MAX_SETTABLE_DURATION_SECS);
+        self.pin_threshold_secs = self.pin_threshold_secs.clamp(0, MAX_SETTABLE_DURATION_SECS);
+
+        if self.min_duration_secs > self.max_duration_secs {
+            std::mem::swap(&mut self.min_duration_secs, &mut self.max_duration_secs);
+        }
+
+        self.default_duration_secs = self
+            .default_duration_secs
+            .clamp(self.min_duration_secs, self.max_duration_secs);
+    }
+}
+
+#[derive(Clone, Copy, Default, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Language {
+    #[default]
+    De,
+    En,
+}
+
+impl Language {
+    pub fn parse(s: &str) -> Option<Self> {
+        match s.to_lowercase().as_str() {
+            "de" => Some(Self::De),
+            "en" => Some(Self::En),
+            _ => None,
+        }
+    }
+
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Self::De => "de",
+            Self::En => "en",
+        }
+    }
+}
+
+/// TypeMap key caching each guild's [`GuildConfig`] once loaded from disk.
+///
+/// Inserted into `ctx.data` via `ClientBuilder::type_map_insert` in `main` before the
+/// client is started.
+pub struct GuildConfigsKey;
+
+impl TypeMapKey for GuildConfigsKey {
+    type Value = Arc<DashMap<GuildId, GuildConfig>>;
+}
+
+/// Returns the cached config for `guild_id`, loading it from disk (or falling back to
+/// the default) on first access.
+pub async fn get_or_load(ctx: &Context, guild_id: GuildId) -> GuildConfig {
+    let cache = {
+        let data = ctx.data.read().await;
+        data.get::<GuildConfigsKey>()
+            .expect("GuildConfigsKey inserted at startup")
+            .clone()
+    };
+
+    if let Some(config) = cache.get(&guild_id) {
+        return config.clone();
+    }
+
+    let config = storage::load(CONFIG_DIR, guild_id).await;
+    cache.insert(guild_id, config.clone());
+    config
+}
+
+/// Persists `config` for `guild_id` to disk and updates the cache.
+pub async fn set(ctx: &Context, guild_id: GuildId, config: GuildConfig) -> std::io::Result<()> {
+    storage::save(CONFIG_DIR, guild_id, config.clone()).await?;
+
+    let cache = {
+        let data = ctx.data.read().await;
+        data.get::<GuildConfigsKey>()
+            .expect("GuildConfigsKey inserted at startup")
+            .clone()
+    };
+    cache.insert(guild_id, config);
+
+    Ok(())
+}
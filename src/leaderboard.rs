@@ -0,0 +1,96 @@
+use dashmap::DashMap;
+use serde::{Deserialize, Serialize};
+use serenity::client::Context;
+use serenity::model::id::{GuildId, UserId};
+use serenity::prelude::TypeMapKey;
+use std::collections::HashMap;
+use std::sync::Arc;
+
+use crate::storage;
+use crate::SpamCount;
+
+const LEADERBOARD_DIR: &str = "leaderboard";
+
+/// A guild's all-time totals, accumulated across every finished contest.
+pub type Leaderboard = HashMap<UserId, SpamCount>;
+
+/// TypeMap key caching each guild's [`Leaderboard`] once loaded from disk.
+///
+/// Inserted into `ctx.data` via `ClientBuilder::type_map_insert` in `main` before the
+/// client is started.
+pub struct LeaderboardsKey;
+
+impl TypeMapKey for LeaderboardsKey {
+    type Value = Arc<DashMap<GuildId, Leaderboard>>;
+}
+
+/// On-disk representation of a [`Leaderboard`]. TOML doesn't allow non-string map keys,
+/// so user ids are stored as their `u64` snowflake written out as a string instead of
+/// `UserId`.
+#[derive(Default, Serialize, Deserialize)]
+struct LeaderboardFile {
+    totals: HashMap<String, SpamCount>,
+}
+
+fn to_leaderboard(file: LeaderboardFile) -> Leaderboard {
+    file.totals
+        .into_iter()
+        .filter_map(|(id, count)| Some((UserId::new(id.parse().ok()?), count)))
+        .collect()
+}
+
+fn to_file(leaderboard: &Leaderboard) -> LeaderboardFile {
+    LeaderboardFile {
+        totals: leaderboard
+            .iter()
+            .map(|(id, count)| (id.get().to_string(), count.clone()))
+            .collect(),
+    }
+}
+
+/// Returns the cached all-time leaderboard for `guild_id`, loading it from disk on
+/// first access.
+pub async fn get_or_load(ctx: &Context, guild_id: GuildId) -> Leaderboard {
+    let cache = {
+        let data = ctx.data.read().await;
+        data.get::<LeaderboardsKey>()
+            .expect("LeaderboardsKey inserted at startup")
+            .clone()
+    };
+
+    if let Some(board) = cache.get(&guild_id) {
+        return board.clone();
+    }
+
+    let board = to_leaderboard(storage::load(LEADERBOARD_DIR, guild_id).await);
+    cache.insert(guild_id, board.clone());
+    board
+}
+
+/// Folds a finished contest's results into the guild's all-time totals and persists
+/// the updated leaderboard to disk.
+pub async fn record_contest(
+    ctx: &Context,
+    guild_id: GuildId,
+    contest_counts: &HashMap<UserId, SpamCount>,
+) -> std::io::Result<()> {
+    let mut board = get_or_load(ctx, guild_id).await;
+
+    for (user_id, count) in contest_counts {
+        let total = board.entry(*user_id).or_default();
+        total.messages += count.messages;
+        total.characters += count.characters;
+    }
+
+    storage::save(LEADERBOARD_DIR, guild_id, to_file(&board)).await?;
+
+    let cache = {
+        let data = ctx.data.read().await;
+        data.get::<LeaderboardsKey>()
+            .expect("LeaderboardsKey inserted at startup")
+            .clone()
+    };
+    cache.insert(guild_id, board);
+
+    Ok(())
+}
@@ -1,13 +1,17 @@
 use dashmap::DashMap;
 use itertools::Itertools;
 use log::{debug, error, info};
-use serenity::all::{ActivityData, CreateEmbed, CreateMessage};
+use serenity::all::{
+    ActivityData, Command, CommandInteraction, CommandOptionType, CreateCommand,
+    CreateCommandOption, CreateEmbed, CreateInteractionResponse, CreateInteractionResponseMessage,
+    CreateMessage, Interaction, ResolvedOption, ResolvedValue,
+};
 use serenity::client::{Context, EventHandler};
 use serenity::model::channel::Message;
 use serenity::model::colour::Colour;
-use serenity::model::event::ResumedEvent;
+use serenity::model::event::{MessageUpdateEvent, ResumedEvent};
 use serenity::model::gateway::Ready;
-use serenity::model::id::{ChannelId, UserId};
+use serenity::model::id::{ChannelId, GuildId, MessageId, UserId};
 use std::cmp::Reverse;
 use std::collections::HashMap;
 use std::fmt::Display;
@@ -16,6 +20,12 @@ use std::time::Duration;
 use time::OffsetDateTime;
 use tokio::sync::mpsc;
 
+pub mod config;
+pub mod leaderboard;
+mod storage;
+
+use config::{GuildConfig, Language};
+
 const DEFAULT_CONTEST_DURATION: Duration = Duration::from_secs(60);
 
 const ALLOWED_DURATION_RANGE: RangeInclusive<Duration> =
@@ -23,7 +33,23 @@ const ALLOWED_DURATION_RANGE: RangeInclusive<Duration> =
 
 const PIN_ANNOUNCEMENT_THRESHOLD: Duration = Duration::from_secs(5 * 60);
 
-type Contests = DashMap<ChannelId, mpsc::Sender<Message>>;
+const COMMAND_NAME: &str = "spamcontest";
+
+/// Max rows rendered in the all-time leaderboard embed fields. Unlike a single
+/// contest's ranking, the leaderboard accumulates every user who ever participated in
+/// a guild, so it needs an explicit cap to stay under Discord's 1024-character embed
+/// field limit.
+const LEADERBOARD_DISPLAY_LIMIT: usize = 20;
+
+type Contests = DashMap<ChannelId, mpsc::Sender<ContestEvent>>;
+
+/// A single message-level event forwarded from the gateway handlers to the contest
+/// currently running in the message's channel, if any.
+enum ContestEvent {
+    Created(Message),
+    Edited { id: MessageId, new_content: String },
+    Deleted(MessageId),
+}
 
 #[derive(Default)]
 pub struct Handler {
@@ -34,11 +60,219 @@ impl Handler {
     pub fn new() -> Self {
         Self::default()
     }
+
+    async fn handle_start_command(&self, ctx: Context, command: CommandInteraction) {
+        let channel_id = command.channel_id;
+
+        let Some(guild_id) = command.guild_id else {
+            respond(&ctx, &command, Strings::for_language(Language::default()).not_in_guild).await;
+            return;
+        };
+
+        let guild_config = config::get_or_load(&ctx, guild_id).await;
+        let text = Strings::for_language(guild_config.language);
+
+        if self.contests.contains_key(&channel_id) {
+            respond(&ctx, &command, text.already_running).await;
+            return;
+        }
+
+        let options = command.data.options();
+        let duration = options
+            .iter()
+            .find(|opt| opt.name == "duration")
+            .and_then(|opt| match opt.value {
+                ResolvedValue::Integer(secs) if secs >= 0 => Some(Duration::from_secs(secs as u64)),
+                _ => None,
+            })
+            .filter(|d| guild_config.allowed_duration_range().contains(d))
+            .unwrap_or_else(|| guild_config.default_duration());
+
+        let metrics = options
+            .iter()
+            .find(|opt| opt.name == "metric")
+            .and_then(|opt| match opt.value {
+                ResolvedValue::String(s) => Metric::parse(s),
+                _ => None,
+            })
+            .map(|metric| vec![metric])
+            .unwrap_or_else(|| DEFAULT_METRICS.to_vec());
+
+        info!(
+            "User {} started a {} second contest in channel {}",
+            command.user.tag(),
+            duration.as_secs(),
+            channel_id.get()
+        );
+
+        respond(&ctx, &command, &(text.start_confirmation)(duration.as_secs())).await;
+
+        match run_contest(
+            ctx.clone(),
+            channel_id,
+            duration,
+            &self.contests,
+            duration >= guild_config.pin_threshold(),
+            guild_config.language,
+            &metrics,
+        )
+        .await
+        {
+            Ok(contest) => {
+                debug!(
+                    "Contest in channel {} has ended with {} participant(s)",
+                    channel_id.get(),
+                    contest.counts.len()
+                );
+                if let Err(err) = leaderboard::record_contest(&ctx, guild_id, &contest.counts).await {
+                    error!("Unable to persist leaderboard for guild {}: {}", guild_id.get(), err);
+                }
+            }
+            Err(err) => error!("Error while running contest in channel {}: {}", channel_id.get(), err),
+        };
+    }
+
+    async fn handle_status_command(&self, ctx: Context, command: CommandInteraction) {
+        let language = match command.guild_id {
+            Some(guild_id) => config::get_or_load(&ctx, guild_id).await.language,
+            None => Language::default(),
+        };
+        let text = Strings::for_language(language);
+
+        let message = if self.contests.contains_key(&command.channel_id) {
+            text.status_running
+        } else {
+            text.status_idle
+        };
+
+        respond(&ctx, &command, message).await;
+    }
+
+    async fn handle_leaderboard_command(&self, ctx: Context, command: CommandInteraction) {
+        let Some(guild_id) = command.guild_id else {
+            respond(&ctx, &command, Strings::for_language(Language::default()).not_in_guild).await;
+            return;
+        };
+
+        let guild_config = config::get_or_load(&ctx, guild_id).await;
+        let text = Strings::for_language(guild_config.language);
+
+        let totals = leaderboard::get_or_load(&ctx, guild_id).await;
+        if totals.is_empty() {
+            respond(&ctx, &command, text.leaderboard_empty).await;
+            return;
+        }
+
+        // Reuse Contest::ranking_by for the tie-aware grouping logic it already has.
+        let board = Contest {
+            counts: totals,
+            tracked_messages: HashMap::new(),
+        };
+
+        let builder = CreateInteractionResponse::Message(
+            CreateInteractionResponseMessage::new().add_embed(
+                CreateEmbed::new()
+                    .title(text.leaderboard_title)
+                    .colour(Colour::GOLD)
+                    .field(
+                        text.leaderboard_by_messages,
+                        board.ranking_by(
+                            |c| Reverse(c.messages),
+                            |c| c.messages,
+                            Some(LEADERBOARD_DISPLAY_LIMIT),
+                            &text,
+                        ),
+                        false,
+                    )
+                    .field(
+                        text.leaderboard_by_characters,
+                        board.ranking_by(
+                            |c| Reverse(c.characters),
+                            |c| c.characters,
+                            Some(LEADERBOARD_DISPLAY_LIMIT),
+                            &text,
+                        ),
+                        false,
+                    ),
+            ),
+        );
+
+        if let Err(err) = command.create_response(&ctx.http, builder).await {
+            error!("Unable to respond to interaction: {}", err);
+        }
+    }
+
+    async fn handle_settings_command(&self, ctx: Context, command: CommandInteraction) {
+        let Some(guild_id) = command.guild_id else {
+            respond(&ctx, &command, Strings::for_language(Language::default()).not_in_guild).await;
+            return;
+        };
+
+        let sub_options = match command.data.options().into_iter().next() {
+            Some(ResolvedOption {
+                value: ResolvedValue::SubCommand(opts),
+                ..
+            }) => opts,
+            _ => Vec::new(),
+        };
+
+        let mut guild_config = config::get_or_load(&ctx, guild_id).await;
+        let text = Strings::for_language(guild_config.language);
+
+        if sub_options.is_empty() {
+            respond(&ctx, &command, &(text.settings_display)(&guild_config)).await;
+            return;
+        }
+
+        let can_manage_guild = command
+            .member
+            .as_ref()
+            .and_then(|member| member.permissions)
+            .is_some_and(|permissions| permissions.manage_guild());
+
+        if !can_manage_guild {
+            respond(&ctx, &command, text.settings_permission_denied).await;
+            return;
+        }
+
+        for opt in &sub_options {
+            match (opt.name, &opt.value) {
+                ("default_duration", ResolvedValue::Integer(secs)) => {
+                    guild_config.default_duration_secs = *secs as u64
+                }
+                ("min_duration", ResolvedValue::Integer(secs)) => {
+                    guild_config.min_duration_secs = *secs as u64
+                }
+                ("max_duration", ResolvedValue::Integer(secs)) => {
+                    guild_config.max_duration_secs = *secs as u64
+                }
+                ("pin_threshold", ResolvedValue::Integer(secs)) => {
+                    guild_config.pin_threshold_secs = *secs as u64
+                }
+                ("language", ResolvedValue::String(lang)) => {
+                    if let Some(language) = Language::parse(lang) {
+                        guild_config.language = language;
+                    }
+                }
+                _ => {}
+            }
+        }
+
+        guild_config.clamp();
+
+        match config::set(&ctx, guild_id, guild_config).await {
+            Ok(()) => respond(&ctx, &command, text.settings_saved).await,
+            Err(err) => {
+                error!("Unable to save guild config for {}: {}", guild_id.get(), err);
+                respond(&ctx, &command, text.settings_save_failed).await;
+            }
+        }
+    }
 }
 
 #[serenity::async_trait]
 impl EventHandler for Handler {
-    async fn message(&self, ctx: Context, msg: Message) {
+    async fn message(&self, _ctx: Context, msg: Message) {
         if let Some(contest) = self.contests.get(&msg.channel_id) {
             debug!(
                 "Counting message {} (from {} in channel {})",
@@ -46,52 +280,85 @@ impl EventHandler for Handler {
                 msg.author.tag(),
                 msg.channel_id.get()
             );
-            contest.value().send(msg).await.unwrap();
+            contest.value().send(ContestEvent::Created(msg)).await.unwrap();
+        }
+    }
+
+    async fn message_update(
+        &self,
+        _ctx: Context,
+        _old_if_available: Option<Message>,
+        _new: Option<Message>,
+        event: MessageUpdateEvent,
+    ) {
+        let Some(new_content) = event.content else {
+            // Other fields (embeds, reactions, ...) changed; the text is unaffected.
             return;
+        };
+
+        if let Some(contest) = self.contests.get(&event.channel_id) {
+            contest
+                .value()
+                .send(ContestEvent::Edited {
+                    id: event.id,
+                    new_content,
+                })
+                .await
+                .unwrap();
         }
+    }
 
-        if msg.content.to_lowercase().contains("spam") {
-            let duration = msg
-                .content
-                .split_ascii_whitespace()
-                .filter_map(|w| w.parse().ok())
-                .map(Duration::from_secs)
-                .find(|d| ALLOWED_DURATION_RANGE.contains(d))
-                .unwrap_or(DEFAULT_CONTEST_DURATION);
+    async fn message_delete(
+        &self,
+        _ctx: Context,
+        channel_id: ChannelId,
+        deleted_message_id: MessageId,
+        _guild_id: Option<GuildId>,
+    ) {
+        if let Some(contest) = self.contests.get(&channel_id) {
+            contest
+                .value()
+                .send(ContestEvent::Deleted(deleted_message_id))
+                .await
+                .unwrap();
+        }
+    }
 
-            info!(
-                "User {} started a {} second contest in channel {}",
-                msg.author.tag(),
-                duration.as_secs(),
-                msg.channel_id.get()
-            );
+    async fn interaction_create(&self, ctx: Context, interaction: Interaction) {
+        let Interaction::Command(command) = interaction else {
+            return;
+        };
 
-            match run_contest(
-                ctx,
-                msg.channel_id,
-                duration,
-                &self.contests,
-                duration >= PIN_ANNOUNCEMENT_THRESHOLD,
-            )
-            .await
-            {
-                Ok(contest) => debug!(
-                    "Contest in channel {} has ended with {} participant(s)",
-                    msg.channel_id.get(),
-                    contest.counts.len()
-                ),
-                Err(err) => error!(
-                    "Error while running contest in channel {}: {}",
-                    msg.channel_id.get(),
-                    err
-                ),
-            };
+        if command.data.name != COMMAND_NAME {
+            return;
+        }
+
+        match command.data.options().first() {
+            Some(ResolvedOption {
+                name: "start",
+                value: ResolvedValue::SubCommand(_),
+                ..
+            }) => self.handle_start_command(ctx, command).await,
+            Some(ResolvedOption { name: "status", .. }) => {
+                self.handle_status_command(ctx, command).await
+            }
+            Some(ResolvedOption { name: "leaderboard", .. }) => {
+                self.handle_leaderboard_command(ctx, command).await
+            }
+            Some(ResolvedOption { name: "settings", .. }) => {
+                self.handle_settings_command(ctx, command).await
+            }
+            other => error!("Received /{COMMAND_NAME} interaction with unexpected subcommand: {other:?}"),
         }
     }
 
     async fn ready(&self, ctx: Context, ready: Ready) {
         info!("Connected as {}", ready.user.tag());
         ctx.set_activity(Some(ActivityData::listening("Spam")));
+
+        if let Err(err) = Command::set_global_commands(&ctx.http, register_commands()).await {
+            error!("Unable to register slash commands: {}", err);
+        }
     }
 
     async fn resume(&self, _ctx: Context, _: ResumedEvent) {
@@ -99,15 +366,133 @@ impl EventHandler for Handler {
     }
 }
 
-#[derive(Default, PartialEq, Eq)]
-struct SpamCount {
-    messages: usize,
-    characters: usize,
+fn register_commands() -> Vec<CreateCommand> {
+    vec![CreateCommand::new(COMMAND_NAME)
+        .description("Verwalte Spam-Wettbewerbe in diesem Kanal")
+        .add_option(
+            CreateCommandOption::new(
+                CommandOptionType::SubCommand,
+                "start",
+                "Startet einen neuen Spam-Wettbewerb",
+            )
+            .add_sub_option(
+                CreateCommandOption::new(
+                    CommandOptionType::Integer,
+                    "duration",
+                    "Dauer des Wettbewerbs in Sekunden",
+                )
+                // No static min/max here: the effective range is per-guild and
+                // configurable via `/spamcontest settings`, so it's enforced against
+                // `guild_config.allowed_duration_range()` in `handle_start_command`
+                // instead of a value fixed at command-registration time.
+                .required(false),
+            )
+            .add_sub_option(
+                CreateCommandOption::new(
+                    CommandOptionType::String,
+                    "metric",
+                    "Wonach wird gewertet? (Standard: Nachrichten und Zeichen)",
+                )
+                .add_string_choice("Nachrichten", "messages")
+                .add_string_choice("Zeichen", "characters")
+                .add_string_choice("Kombiniert (Nachrichten + Zeichen/10)", "combined")
+                .add_string_choice("Ø Zeichen pro Nachricht", "average")
+                .required(false),
+            ),
+        )
+        .add_option(CreateCommandOption::new(
+            CommandOptionType::SubCommand,
+            "status",
+            "Zeigt den Status eines laufenden Wettbewerbs in diesem Kanal",
+        ))
+        .add_option(CreateCommandOption::new(
+            CommandOptionType::SubCommand,
+            "leaderboard",
+            "Zeigt die Allzeit-Bestenliste dieses Servers",
+        ))
+        .add_option(
+            CreateCommandOption::new(
+                CommandOptionType::SubCommand,
+                "settings",
+                "Zeigt oder ändert die Servereinstellungen für Wettbewerbe",
+            )
+            .add_sub_option(
+                CreateCommandOption::new(
+                    CommandOptionType::Integer,
+                    "default_duration",
+                    "Neue Standarddauer in Sekunden",
+                )
+                .min_int_value(1)
+                .max_int_value(config::MAX_SETTABLE_DURATION_SECS)
+                .required(false),
+            )
+            .add_sub_option(
+                CreateCommandOption::new(
+                    CommandOptionType::Integer,
+                    "min_duration",
+                    "Neue minimale Dauer in Sekunden",
+                )
+                .min_int_value(1)
+                .max_int_value(config::MAX_SETTABLE_DURATION_SECS)
+                .required(false),
+            )
+            .add_sub_option(
+                CreateCommandOption::new(
+                    CommandOptionType::Integer,
+                    "max_duration",
+                    "Neue maximale Dauer in Sekunden",
+                )
+                .min_int_value(1)
+                .max_int_value(config::MAX_SETTABLE_DURATION_SECS)
+                .required(false),
+            )
+            .add_sub_option(
+                CreateCommandOption::new(
+                    CommandOptionType::Integer,
+                    "pin_threshold",
+                    "Neue Pin-Schwelle in Sekunden",
+                )
+                .min_int_value(0)
+                .max_int_value(config::MAX_SETTABLE_DURATION_SECS)
+                .required(false),
+            )
+            .add_sub_option(
+                CreateCommandOption::new(
+                    CommandOptionType::String,
+                    "language",
+                    "Ausgabesprache (de/en)",
+                )
+                .add_string_choice("Deutsch", "de")
+                .add_string_choice("English", "en")
+                .required(false),
+            ),
+        )]
+}
+
+async fn respond(ctx: &Context, command: &CommandInteraction, content: &str) {
+    let builder = CreateInteractionResponse::Message(
+        CreateInteractionResponseMessage::new()
+            .content(content)
+            .ephemeral(true),
+    );
+
+    if let Err(err) = command.create_response(&ctx.http, builder).await {
+        error!("Unable to respond to interaction: {}", err);
+    }
+}
+
+#[derive(Default, Clone, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+pub(crate) struct SpamCount {
+    pub(crate) messages: usize,
+    pub(crate) characters: usize,
 }
 
 #[derive(Default)]
 struct Contest {
     counts: HashMap<UserId, SpamCount>,
+    /// Char count of each still-live counted message, keyed by message id, so edits and
+    /// deletes can adjust the author's `SpamCount` instead of only ever incrementing it.
+    tracked_messages: HashMap<MessageId, (UserId, usize)>,
 }
 
 impl Contest {
@@ -115,7 +500,7 @@ impl Contest {
         Self::default()
     }
 
-    fn count(&mut self, message: &Message) {
+    fn count_created(&mut self, message: &Message) {
         let char_count = message.content.chars().count();
         match self.counts.get_mut(&message.author.id) {
             None => {
@@ -132,9 +517,53 @@ impl Contest {
                 count.characters += char_count;
             }
         };
+        self.tracked_messages
+            .insert(message.id, (message.author.id, char_count));
     }
 
-    fn ranking_by<Fk, K, Fd, D>(&self, fk: Fk, fd: Fd) -> String
+    fn count_edited(&mut self, id: MessageId, new_content: &str) {
+        let Some((author, old_char_count)) = self.tracked_messages.get_mut(&id) else {
+            return;
+        };
+        let new_char_count = new_content.chars().count();
+
+        if let Some(count) = self.counts.get_mut(author) {
+            if new_char_count >= *old_char_count {
+                count.characters += new_char_count - *old_char_count;
+            } else {
+                count.characters = count.characters.saturating_sub(*old_char_count - new_char_count);
+            }
+        }
+
+        *old_char_count = new_char_count;
+    }
+
+    fn count_deleted(&mut self, id: MessageId) {
+        let Some((author, char_count)) = self.tracked_messages.remove(&id) else {
+            return;
+        };
+
+        let became_empty = if let Some(count) = self.counts.get_mut(&author) {
+            count.messages = count.messages.saturating_sub(1);
+            count.characters = count.characters.saturating_sub(char_count);
+            *count == SpamCount::default()
+        } else {
+            false
+        };
+
+        // A user whose every message in the contest was deleted shouldn't still show
+        // up in the ranking as a "0" entry.
+        if became_empty {
+            self.counts.remove(&author);
+        }
+    }
+
+    /// Renders the tie-aware ranking as a `"**rank.:** <@user> (score)"` list, sorted by
+    /// `fk` and displayed via `fd`. `limit` caps how many entries are rendered (a
+    /// trailing line notes how many were left out) — pass `None` for an unbounded list,
+    /// which is safe for a single contest but not for e.g. an all-time leaderboard that
+    /// can accumulate far more distinct users than fit in a Discord embed field.
+    fn ranking_by<Fk, K, Fd, D>(&self, fk: Fk, fd: Fd, limit: Option<usize>, text: &Strings) -> String
     where
         Fk: Fn(&SpamCount) -> K,
         K: Ord,
@@ -143,19 +572,30 @@ impl Contest {
     {
         let mut ranking = self.counts.iter().collect::<Vec<_>>();
         ranking.sort_unstable_by_key(|(_, c)| fk(c));
+        let total = ranking.len();
 
         let mut result = String::new();
         let mut cur_rank_num = 1;
-        for (_, rank_group) in &ranking.into_iter().chunk_by(|elt| fk((elt).1)) {
+        let mut shown = 0;
+        'groups: for (_, rank_group) in &ranking.into_iter().chunk_by(|elt| fk((elt).1)) {
             let mut group_size = 0;
             for (userid, count) in rank_group {
+                if limit.is_some_and(|limit| shown >= limit) {
+                    break 'groups;
+                }
                 result.push_str(
                     format!("**{cur_rank_num}.:** <@{userid}> ({})\n", fd(count)).as_str(),
                 );
                 group_size += 1;
+                shown += 1;
             }
             cur_rank_num += group_size;
         }
+
+        if shown < total {
+            result.push_str(&(text.ranking_more)(total - shown));
+        }
+
         result
     }
 }
@@ -166,8 +606,11 @@ async fn run_contest(
     duration: Duration,
     contests: &Contests,
     pin: bool,
+    language: Language,
+    metrics: &[Metric],
 ) -> serenity::Result<Contest> {
     let end_timestamp = (OffsetDateTime::now_utc() + duration).unix_timestamp();
+    let text = Strings::for_language(language);
 
     // send announcement message
     let announcement = channel_id
@@ -175,10 +618,8 @@ async fn run_contest(
             &ctx.http,
             CreateMessage::new().add_embed(
                 CreateEmbed::new()
-                    .title("Es wurde ein Spam-Wettbewerb gestartet!")
-                    .description(format!(
-                        "Wer am meisten spamt, gewinnt.\nEnde <t:{end_timestamp}:R>.",
-                    ))
+                    .title(text.starting_title)
+                    .description((text.starting_description)(end_timestamp))
                     .colour(Colour::BLUE),
             ),
         )
@@ -197,8 +638,12 @@ async fn run_contest(
         tokio::select! {
             _ = tokio::time::sleep(duration) => {},
             _ = async {
-                while let Some(msg) = rx.recv().await {
-                    counts.count(&msg);
+                while let Some(event) = rx.recv().await {
+                    match event {
+                        ContestEvent::Created(msg) => counts.count_created(&msg),
+                        ContestEvent::Edited { id, new_content } => counts.count_edited(id, &new_content),
+                        ContestEvent::Deleted(id) => counts.count_deleted(id),
+                    }
                 }
             } => { unreachable!("mpsc receiver closed unexpectedly") },
         }
@@ -213,28 +658,172 @@ async fn run_contest(
             announcement.unpin(&ctx.http).await.ok();
         }
 
-        // send ranking message
+        // send ranking message, one field per selected metric
+        let embed = metrics.iter().fold(
+            CreateEmbed::new().title(text.ended_title).colour(Colour::DARK_GREEN),
+            |embed, metric| {
+                embed.field(
+                    metric.label(language),
+                    counts.ranking_by(|c| Reverse(metric.score(c)), |c| metric.score(c), None, &text),
+                    false,
+                )
+            },
+        );
+
         channel_id
-            .send_message(
-                &ctx.http,
-                CreateMessage::new().add_embed(
-                    CreateEmbed::new()
-                        .title("Der Wettbewerb ist beendet!")
-                        .colour(Colour::DARK_GREEN)
-                        .field(
-                            "Ergebnisse (nach Nachrichten):",
-                            counts.ranking_by(|c| Reverse(c.messages), |c| c.messages),
-                            false,
-                        )
-                        .field(
-                            "Ergebnisse (nach Zeichen):",
-                            counts.ranking_by(|c| Reverse(c.characters), |c| c.characters),
-                            false,
-                        ),
-                ),
-            )
+            .send_message(&ctx.http, CreateMessage::new().add_embed(embed))
             .await?;
     }
 
     Ok(counts)
 }
+
+/// The translated strings used to render every user-facing reply (ephemeral command
+/// responses as well as a contest's announcement/results embeds), selected once per
+/// interaction via [`GuildConfig::language`].
+struct Strings {
+    not_in_guild: &'static str,
+    already_running: &'static str,
+    start_confirmation: fn(u64) -> String,
+    status_running: &'static str,
+    status_idle: &'static str,
+    leaderboard_empty: &'static str,
+    leaderboard_title: &'static str,
+    leaderboard_by_messages: &'static str,
+    leaderboard_by_characters: &'static str,
+    ranking_more: fn(usize) -> String,
+    settings_display: fn(&GuildConfig) -> String,
+    settings_permission_denied: &'static str,
+    settings_saved: &'static str,
+    settings_save_failed: &'static str,
+    starting_title: &'static str,
+    starting_description: fn(i64) -> String,
+    ended_title: &'static str,
+}
+
+impl Strings {
+    fn for_language(language: Language) -> Self {
+        match language {
+            Language::De => Self {
+                not_in_guild: "Dieser Befehl funktioniert nur auf einem Server.",
+                already_running: "In diesem Kanal läuft bereits ein Wettbewerb.",
+                start_confirmation: |secs| format!("Wettbewerb über {secs} Sekunden gestartet!"),
+                status_running: "Ein Wettbewerb läuft gerade in diesem Kanal.",
+                status_idle: "Aktuell läuft kein Wettbewerb in diesem Kanal.",
+                leaderboard_empty: "In diesem Server wurde noch kein Wettbewerb gewertet.",
+                leaderboard_title: "Allzeit-Bestenliste",
+                leaderboard_by_messages: "Nach Nachrichten:",
+                leaderboard_by_characters: "Nach Zeichen:",
+                ranking_more: |more| format!("*… und {more} weitere*\n"),
+                settings_display: |config| {
+                    format!(
+                        "**Aktuelle Einstellungen**\n\
+                         Standarddauer: {}s\n\
+                         Erlaubter Bereich: {}s - {}s\n\
+                         Pin-Schwelle: {}s\n\
+                         Sprache: {}",
+                        config.default_duration_secs,
+                        config.min_duration_secs,
+                        config.max_duration_secs,
+                        config.pin_threshold_secs,
+                        config.language.as_str(),
+                    )
+                },
+                settings_permission_denied: "Dazu brauchst du die Berechtigung \"Server verwalten\".",
+                settings_saved: "Einstellungen gespeichert.",
+                settings_save_failed: "Einstellungen konnten nicht gespeichert werden.",
+                starting_title: "Es wurde ein Spam-Wettbewerb gestartet!",
+                starting_description: |end_timestamp| {
+                    format!("Wer am meisten spamt, gewinnt.\nEnde <t:{end_timestamp}:R>.")
+                },
+                ended_title: "Der Wettbewerb ist beendet!",
+            },
+            Language::En => Self {
+                not_in_guild: "This command only works in a server.",
+                already_running: "A contest is already running in this channel.",
+                start_confirmation: |secs| format!("Started a {secs} second contest!"),
+                status_running: "A contest is currently running in this channel.",
+                status_idle: "No contest is currently running in this channel.",
+                leaderboard_empty: "No contest has been scored in this server yet.",
+                leaderboard_title: "All-time leaderboard",
+                leaderboard_by_messages: "By messages:",
+                leaderboard_by_characters: "By characters:",
+                ranking_more: |more| format!("*… and {more} more*\n"),
+                settings_display: |config| {
+                    format!(
+                        "**Current settings**\n\
+                         Default duration: {}s\n\
+                         Allowed range: {}s - {}s\n\
+                         Pin threshold: {}s\n\
+                         Language: {}",
+                        config.default_duration_secs,
+                        config.min_duration_secs,
+                        config.max_duration_secs,
+                        config.pin_threshold_secs,
+                        config.language.as_str(),
+                    )
+                },
+                settings_permission_denied: "You need the \"Manage Server\" permission for that.",
+                settings_saved: "Settings saved.",
+                settings_save_failed: "Unable to save the settings.",
+                starting_title: "A spam contest has started!",
+                starting_description: |end_timestamp| {
+                    format!("Whoever spams the most wins.\nEnds <t:{end_timestamp}:R>.")
+                },
+                ended_title: "The contest has ended!",
+            },
+        }
+    }
+}
+
+/// A scoring metric a contest can rank participants by, selectable per contest via the
+/// `metric` option of `/spamcontest start`. Each variant needs a `parse`, `score` and
+/// `label` arm plus a `metric` choice in `register_commands`; `run_contest` asks [`Contest::ranking_by`] for one embed field per
+/// selected metric without otherwise caring which ones were picked.
+#[derive(Clone, Copy)]
+pub(crate) enum Metric {
+    Messages,
+    Characters,
+    /// Rewards both volume and length: `messages + characters / 10`.
+    Combined,
+    /// Average message length in characters.
+    AverageLength,
+}
+
+/// Metrics used when a contest's start command doesn't pick one explicitly, matching
+/// the ranking this bot has always shown.
+const DEFAULT_METRICS: &[Metric] = &[Metric::Messages, Metric::Characters];
+
+impl Metric {
+    fn parse(s: &str) -> Option<Self> {
+        match s {
+            "messages" => Some(Self::Messages),
+            "characters" => Some(Self::Characters),
+            "combined" => Some(Self::Combined),
+            "average" => Some(Self::AverageLength),
+            _ => None,
+        }
+    }
+
+    fn score(self, count: &SpamCount) -> usize {
+        match self {
+            Self::Messages => count.messages,
+            Self::Characters => count.characters,
+            Self::Combined => count.messages + count.characters / 10,
+            Self::AverageLength => count.characters.checked_div(count.messages).unwrap_or(0),
+        }
+    }
+
+    fn label(self, language: Language) -> &'static str {
+        match (self, language) {
+            (Self::Messages, Language::De) => "Ergebnisse (nach Nachrichten):",
+            (Self::Messages, Language::En) => "Results (by messages):",
+            (Self::Characters, Language::De) => "Ergebnisse (nach Zeichen):",
+            (Self::Characters, Language::En) => "Results (by characters):",
+            (Self::Combined, Language::De) => "Ergebnisse (kombiniert):",
+            (Self::Combined, Language::En) => "Results (combined):",
+            (Self::AverageLength, Language::De) => "Ergebnisse (Ø Zeichen/Nachricht):",
+            (Self::AverageLength, Language::En) => "Results (avg. chars/message):",
+        }
+    }
+}
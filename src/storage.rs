@@ -0,0 +1,51 @@
+use log::error;
+use serde::de::DeserializeOwned;
+use serde::Serialize;
+use serenity::model::id::GuildId;
+use std::path::{Path, PathBuf};
+
+fn path_for(dir: &str, guild_id: GuildId) -> PathBuf {
+    Path::new(dir).join(format!("{}.toml", guild_id.get()))
+}
+
+/// Loads a guild-keyed TOML file from `dir`, falling back to `T::default()` if it is
+/// missing or malformed. Runs on the blocking thread pool so a slow disk never stalls
+/// the Tokio worker handling the interaction.
+pub(crate) async fn load<T>(dir: &'static str, guild_id: GuildId) -> T
+where
+    T: Default + DeserializeOwned + Send + 'static,
+{
+    tokio::task::spawn_blocking(move || {
+        let path = path_for(dir, guild_id);
+        match std::fs::read_to_string(&path) {
+            Ok(contents) => toml::from_str(&contents).unwrap_or_else(|err| {
+                error!("Malformed file at {}: {}", path.display(), err);
+                T::default()
+            }),
+            Err(_) => T::default(),
+        }
+    })
+    .await
+    .unwrap_or_else(|err| {
+        error!("Blocking load of {}/{} panicked: {}", dir, guild_id.get(), err);
+        T::default()
+    })
+}
+
+/// Persists `value` as a guild-keyed TOML file under `dir`, creating the directory if
+/// needed. Same blocking-thread-pool rationale as [`load`].
+pub(crate) async fn save<T>(dir: &'static str, guild_id: GuildId, value: T) -> std::io::Result<()>
+where
+    T: Serialize + Send + 'static,
+{
+    tokio::task::spawn_blocking(move || {
+        std::fs::create_dir_all(dir)?;
+        let contents = toml::to_string_pretty(&value).expect("value always serializes");
+        std::fs::write(path_for(dir, guild_id), contents)
+    })
+    .await
+    .unwrap_or_else(|err| {
+        error!("Blocking save of {}/{} panicked: {}", dir, guild_id.get(), err);
+        Err(std::io::Error::other(err))
+    })
+}